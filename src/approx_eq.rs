@@ -0,0 +1,118 @@
+//! Epsilon-aware approximate equality for math types.
+
+use crate::{Quat, Scalar, Vec2, Vec3};
+
+/// Approximate equality with a sensible default epsilon.
+///
+/// Exact `PartialEq` comparisons are often too strict for floating-point state
+/// replicated through SpacetimeDB, since that state accumulates rounding error
+/// as it's read, written, and re-derived across reducer calls.
+pub trait ApproxEq: Sized {
+    /// The default epsilon used by [`ApproxEq::approx_eq`].
+    const EPSILON: Self;
+
+    /// Returns `true` if `self` and `other` are equal within [`ApproxEq::EPSILON`].
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.approx_eq_eps(other, &Self::EPSILON)
+    }
+
+    /// Returns `true` if `self` and `other` are equal within `eps`.
+    fn approx_eq_eps(&self, other: &Self, eps: &Self) -> bool;
+}
+
+impl ApproxEq for Scalar {
+    #[cfg(feature = "f32")]
+    const EPSILON: Scalar = 1.0e-5;
+    #[cfg(feature = "f64")]
+    const EPSILON: Scalar = 1.0e-10;
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Scalar, eps: &Scalar) -> bool {
+        (self - other).abs() <= *eps
+    }
+}
+
+impl ApproxEq for Vec2 {
+    const EPSILON: Vec2 = Vec2::new(
+        <Scalar as ApproxEq>::EPSILON,
+        <Scalar as ApproxEq>::EPSILON,
+    );
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Vec2, eps: &Vec2) -> bool {
+        self.x.approx_eq_eps(&other.x, &eps.x) && self.y.approx_eq_eps(&other.y, &eps.y)
+    }
+}
+
+impl ApproxEq for Vec3 {
+    const EPSILON: Vec3 = Vec3::new(
+        <Scalar as ApproxEq>::EPSILON,
+        <Scalar as ApproxEq>::EPSILON,
+        <Scalar as ApproxEq>::EPSILON,
+    );
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Vec3, eps: &Vec3) -> bool {
+        self.x.approx_eq_eps(&other.x, &eps.x)
+            && self.y.approx_eq_eps(&other.y, &eps.y)
+            && self.z.approx_eq_eps(&other.z, &eps.z)
+    }
+}
+
+impl ApproxEq for Quat {
+    const EPSILON: Quat = Quat::new(
+        <Scalar as ApproxEq>::EPSILON,
+        <Scalar as ApproxEq>::EPSILON,
+        <Scalar as ApproxEq>::EPSILON,
+        <Scalar as ApproxEq>::EPSILON,
+    );
+
+    /// `q` and `-q` represent the same rotation, so either sign is accepted.
+    #[inline]
+    fn approx_eq_eps(&self, other: &Quat, eps: &Quat) -> bool {
+        let same = self.x.approx_eq_eps(&other.x, &eps.x)
+            && self.y.approx_eq_eps(&other.y, &eps.y)
+            && self.z.approx_eq_eps(&other.z, &eps.z)
+            && self.w.approx_eq_eps(&other.w, &eps.w);
+        let negated = self.x.approx_eq_eps(&-other.x, &eps.x)
+            && self.y.approx_eq_eps(&-other.y, &eps.y)
+            && self.z.approx_eq_eps(&-other.z, &eps.z)
+            && self.w.approx_eq_eps(&-other.w, &eps.w);
+        same || negated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scalar_approx_eq_within_default_epsilon() {
+        let a = 1.0 as Scalar;
+        let b = a + <Scalar as ApproxEq>::EPSILON * 0.5;
+        assert!(a.approx_eq(&b));
+    }
+
+    #[test]
+    fn scalar_approx_eq_rejects_beyond_epsilon() {
+        let a = 1.0 as Scalar;
+        let b = a + 1.0 as Scalar;
+        assert!(!a.approx_eq(&b));
+    }
+
+    #[test]
+    fn vec3_approx_eq_is_componentwise() {
+        let a = Vec3::new(1.0 as Scalar, 2.0 as Scalar, 3.0 as Scalar);
+        let b = Vec3::new(1.0 as Scalar, 2.0 as Scalar, 3.1 as Scalar);
+        assert!(!a.approx_eq(&b));
+        let loose = Vec3::new(0.2 as Scalar, 0.2 as Scalar, 0.2 as Scalar);
+        assert!(a.approx_eq_eps(&b, &loose));
+    }
+
+    #[test]
+    fn quat_approx_eq_treats_negated_quat_as_equal() {
+        let q = Quat::new(0.1 as Scalar, 0.2 as Scalar, 0.3 as Scalar, 0.9 as Scalar);
+        let negated = Quat::new(-q.x, -q.y, -q.z, -q.w);
+        assert!(q.approx_eq(&negated));
+    }
+}
@@ -1,6 +1,6 @@
 //! Coordinate system conventions and axis presets.
 
-use crate::{Scalar, Vec3};
+use crate::{Quat, Scalar, Vec3};
 
 /// Orthonormal axes describing a coordinate system's orientation.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -33,6 +33,144 @@ impl Axes {
         let forward = right.cross(up);
         Some(Self { up, forward, right })
     }
+
+    /// Converts a vector expressed in this convention into the equivalent vector
+    /// in `to`'s convention.
+    ///
+    /// Decomposes `v` into right/up/forward coordinates using this convention
+    /// (valid because the bases are orthonormal), then recomposes those same
+    /// coordinates using `to`'s axes. This correctly handles axis swaps and
+    /// handedness flips, e.g. mapping a Unity client position into a Bevy server.
+    ///
+    /// # Examples
+    /// ```
+    /// use spacetimedb_math::{Vec3, conventions};
+    ///
+    /// // Unity's +Z forward becomes Bevy's -Z forward.
+    /// let unity_forward = Vec3::new(0.0, 0.0, 1.0);
+    /// let bevy_forward = conventions::Y_UP_LEFT_HANDED_FWD_POS_Z
+    ///     .convert_vec(unity_forward, &conventions::Y_UP_RIGHT_HANDED_FWD_NEG_Z);
+    /// assert_eq!(bevy_forward, Vec3::new(0.0, 0.0, -1.0));
+    /// ```
+    pub fn convert_vec(&self, v: Vec3, to: &Axes) -> Vec3 {
+        let r = v.dot(self.right);
+        let u = v.dot(self.up);
+        let f = v.dot(self.forward);
+        let right = scale(to.right, r);
+        let up = scale(to.up, u);
+        let forward = scale(to.forward, f);
+        Vec3::new(
+            right.x + up.x + forward.x,
+            right.y + up.y + forward.y,
+            right.z + up.z + forward.z,
+        )
+    }
+
+    /// Converts a rotation expressed in this convention into the equivalent
+    /// rotation in `to`'s convention.
+    ///
+    /// Builds the change-of-basis matrix between the two conventions and
+    /// conjugates the quaternion's rotation matrix by it. Prefer
+    /// [`ConventionTransform`] when converting many rotations, since it
+    /// precomputes the change-of-basis matrix once.
+    pub fn convert_quat(&self, q: Quat, to: &Axes) -> Quat {
+        apply_basis_change(basis_matrix(self, to), q)
+    }
+}
+
+/// A precomputed transform between two coordinate conventions.
+///
+/// [`Axes::convert_quat`] recomputes the change-of-basis matrix on every call;
+/// building a `ConventionTransform` once and reusing it avoids that cost in a
+/// hot reducer that converts many rotations per tick.
+/// [`ConventionTransform::convert_vec`] does the same per-call work as
+/// [`Axes::convert_vec`].
+///
+/// # Examples
+/// ```
+/// use spacetimedb_math::{Vec3, conventions::{self, ConventionTransform}};
+///
+/// let unity_to_bevy = ConventionTransform::new(
+///     conventions::Y_UP_LEFT_HANDED_FWD_POS_Z,
+///     conventions::Y_UP_RIGHT_HANDED_FWD_NEG_Z,
+/// );
+/// let unity_forward = Vec3::new(0.0, 0.0, 1.0);
+/// assert_eq!(unity_to_bevy.convert_vec(unity_forward), Vec3::new(0.0, 0.0, -1.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConventionTransform {
+    from: Axes,
+    to: Axes,
+    basis: Mat3,
+}
+
+impl ConventionTransform {
+    /// Precomputes a transform that converts vectors and rotations from `from`'s
+    /// convention into `to`'s convention.
+    pub fn new(from: Axes, to: Axes) -> Self {
+        let basis = basis_matrix(&from, &to);
+        Self { from, to, basis }
+    }
+
+    /// Converts a vector expressed in the source convention into the destination convention.
+    pub fn convert_vec(&self, v: Vec3) -> Vec3 {
+        self.from.convert_vec(v, &self.to)
+    }
+
+    /// Converts a rotation expressed in the source convention into the destination convention.
+    pub fn convert_quat(&self, q: Quat) -> Quat {
+        apply_basis_change(self.basis, q)
+    }
+}
+
+/// A 3x3 matrix stored as its columns, used internally to change the basis a
+/// rotation is expressed relative to. Not exposed publicly: every caller only
+/// needs vector/quaternion conversion, not general matrix algebra.
+type Mat3 = (Vec3, Vec3, Vec3);
+
+#[inline]
+fn scale(v: Vec3, s: Scalar) -> Vec3 {
+    Vec3::new(v.x * s, v.y * s, v.z * s)
+}
+
+/// Builds the change-of-basis matrix that maps vectors from `src`'s convention
+/// into `to`'s, i.e. whose columns are where [`Axes::convert_vec`] sends the
+/// `X`, `Y`, and `Z` unit vectors.
+fn basis_matrix(src: &Axes, to: &Axes) -> Mat3 {
+    let convert = |v: Vec3| src.convert_vec(v, to);
+    (convert(Vec3::X), convert(Vec3::Y), convert(Vec3::Z))
+}
+
+fn mat3_mulv(m: Mat3, v: Vec3) -> Vec3 {
+    Vec3::new(
+        m.0.x * v.x + m.1.x * v.y + m.2.x * v.z,
+        m.0.y * v.x + m.1.y * v.y + m.2.y * v.z,
+        m.0.z * v.x + m.1.z * v.y + m.2.z * v.z,
+    )
+}
+
+fn mat3_mul(a: Mat3, b: Mat3) -> Mat3 {
+    (mat3_mulv(a, b.0), mat3_mulv(a, b.1), mat3_mulv(a, b.2))
+}
+
+fn mat3_transpose(m: Mat3) -> Mat3 {
+    (
+        Vec3::new(m.0.x, m.1.x, m.2.x),
+        Vec3::new(m.0.y, m.1.y, m.2.y),
+        Vec3::new(m.0.z, m.1.z, m.2.z),
+    )
+}
+
+/// Conjugates a quaternion's rotation matrix by `basis`.
+///
+/// `basis * R * basis^T` is already a determinant-+1 rotation matrix for any
+/// orthogonal `basis`, regardless of `basis`'s own handedness (det(basis)^2 = 1
+/// cancels out), so no extra sign correction is needed here.
+fn apply_basis_change(basis: Mat3, q: Quat) -> Quat {
+    let (col_x, col_y, col_z) = q.to_basis();
+    let r: Mat3 = (col_x, col_y, col_z);
+    let conjugated = mat3_mul(mat3_mul(basis, r), mat3_transpose(basis));
+    Quat::from_basis(conjugated.0, conjugated.1, conjugated.2)
 }
 
 /// Default coordinate convention.
@@ -131,3 +269,73 @@ pub const Z_UP_LEFT_HANDED_FWD_POS_X: Axes = Axes {
     forward: Vec3::new(1.0, 0.0, 0.0),
     right: Vec3::new(0.0, 1.0, 0.0),
 };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ApproxEq;
+
+    #[test]
+    fn convert_vec_is_identity_for_same_convention() {
+        let v = Vec3::new(1.0 as Scalar, 2.0 as Scalar, 3.0 as Scalar);
+        assert_eq!(DEFAULT.convert_vec(v, &DEFAULT), v);
+    }
+
+    #[test]
+    fn convert_vec_maps_unity_forward_to_bevy_forward() {
+        let unity_forward = Vec3::new(0.0 as Scalar, 0.0 as Scalar, 1.0 as Scalar);
+        let bevy_forward = Y_UP_LEFT_HANDED_FWD_POS_Z.convert_vec(unity_forward, &DEFAULT);
+        assert_eq!(bevy_forward, Vec3::new(0.0 as Scalar, 0.0 as Scalar, -1.0 as Scalar));
+    }
+
+    #[test]
+    fn convert_quat_is_identity_for_same_convention() {
+        let q = Quat::new(0.1 as Scalar, 0.2 as Scalar, 0.3 as Scalar, 0.9 as Scalar)
+            .normalize_or_identity(1.0e-5 as Scalar);
+        let converted = DEFAULT.convert_quat(q, &DEFAULT);
+        let epsilon = 1.0e-5 as Scalar;
+        assert!((converted.dot(q).abs() - 1.0 as Scalar).abs() <= epsilon);
+    }
+
+    #[test]
+    fn convention_transform_matches_per_call_conversion() {
+        let transform = ConventionTransform::new(Y_UP_LEFT_HANDED_FWD_POS_Z, DEFAULT);
+        let v = Vec3::new(1.0 as Scalar, 2.0 as Scalar, 3.0 as Scalar);
+        assert_eq!(
+            transform.convert_vec(v),
+            Y_UP_LEFT_HANDED_FWD_POS_Z.convert_vec(v, &DEFAULT)
+        );
+
+        let q = Quat::IDENTITY;
+        let epsilon = 1.0e-5 as Scalar;
+        let from_transform = transform.convert_quat(q);
+        let from_axes = Y_UP_LEFT_HANDED_FWD_POS_Z.convert_quat(q, &DEFAULT);
+        assert!((from_transform.dot(from_axes).abs() - 1.0 as Scalar).abs() <= epsilon);
+    }
+
+    #[test]
+    fn convert_quat_matches_converting_a_rotated_vector_across_handedness() {
+        // A non-axis-aligned rotation, converted between conventions of
+        // different handedness (left-handed Unity -> right-handed DEFAULT):
+        // rotating in the source convention then converting the resulting
+        // vector must agree with converting the quaternion first and rotating
+        // in the destination convention.
+        let half_angle = (0.4 as Scalar) / 2.0;
+        let axis = Vec3::new(1.0 as Scalar, 2.0 as Scalar, 3.0 as Scalar)
+            .try_normalize(1.0e-5 as Scalar)
+            .unwrap();
+        let q = Quat::new(
+            axis.x * half_angle.sin(),
+            axis.y * half_angle.sin(),
+            axis.z * half_angle.sin(),
+            half_angle.cos(),
+        );
+        let v = Vec3::new(0.5 as Scalar, -1.0 as Scalar, 2.0 as Scalar);
+
+        let rotate_then_convert = Y_UP_LEFT_HANDED_FWD_POS_Z.convert_vec(q * v, &DEFAULT);
+        let convert_then_rotate = Y_UP_LEFT_HANDED_FWD_POS_Z.convert_quat(q, &DEFAULT)
+            * Y_UP_LEFT_HANDED_FWD_POS_Z.convert_vec(v, &DEFAULT);
+
+        assert!(rotate_then_convert.approx_eq(&convert_then_rotate));
+    }
+}
@@ -24,6 +24,7 @@
 #[cfg(all(feature = "f32", feature = "f64"))]
 compile_error!("Features 'f32' and 'f64' are mutually exclusive.");
 
+pub mod approx_eq;
 pub mod conventions;
 pub mod quat;
 pub mod scalar;
@@ -32,6 +33,7 @@ pub mod timing;
 pub mod vec2;
 pub mod vec3;
 
+pub use approx_eq::*;
 pub use quat::*;
 pub use scalar::*;
 #[cfg(feature = "timing")]
@@ -0,0 +1,324 @@
+use crate::{conventions::Axes, ApproxEq, Scalar, Vec3};
+use spacetimedb::SpacetimeType;
+use std::ops::Mul;
+
+/// A rotation represented as a unit quaternion with `x`, `y`, `z`, `w` components.
+///
+/// # Examples
+/// ```
+/// use spacetimedb_math::Quat;
+///
+/// let q = Quat::IDENTITY;
+/// assert_eq!(q.w, 1.0);
+/// ```
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quat {
+    /// X component of the vector part.
+    pub x: Scalar,
+    /// Y component of the vector part.
+    pub y: Scalar,
+    /// Z component of the vector part.
+    pub z: Scalar,
+    /// W component (the scalar part).
+    pub w: Scalar,
+}
+
+impl Default for Quat {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Quat {
+    /// The identity rotation.
+    pub const IDENTITY: Quat = Quat::new(0.0, 0.0, 0.0, 1.0);
+
+    #[inline(always)]
+    pub const fn new(x: Scalar, y: Scalar, z: Scalar, w: Scalar) -> Self {
+        Quat { x, y, z, w }
+    }
+
+    /// Returns the dot product of this quaternion and `other`.
+    #[inline]
+    pub fn dot(&self, other: Quat) -> Scalar {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Returns the squared length (magnitude) of this quaternion.
+    #[inline]
+    pub fn length_squared(&self) -> Scalar {
+        self.dot(*self)
+    }
+
+    /// Returns the length (magnitude) of this quaternion.
+    pub fn length(&self) -> Scalar {
+        self.length_squared().sqrt()
+    }
+
+    /// Returns the conjugate of this quaternion, i.e. the vector part negated.
+    ///
+    /// For a unit quaternion this is equal to the inverse rotation.
+    #[inline]
+    pub fn conjugate(&self) -> Quat {
+        Quat::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns a normalized quaternion, or `Quat::IDENTITY` if length is below `epsilon`.
+    pub fn normalize_or_identity(&self, epsilon: Scalar) -> Quat {
+        let len_sq = self.length_squared();
+        let epsilon_sq = epsilon * epsilon;
+        if len_sq <= epsilon_sq {
+            Quat::IDENTITY
+        } else {
+            let len = len_sq.sqrt();
+            Quat::new(self.x / len, self.y / len, self.z / len, self.w / len)
+        }
+    }
+
+    /// Attempts to normalize this quaternion, returning `None` if length is below `epsilon`.
+    pub fn try_normalize(&self, epsilon: Scalar) -> Option<Quat> {
+        let len_sq = self.length_squared();
+        let epsilon_sq = epsilon * epsilon;
+        if len_sq <= epsilon_sq {
+            None
+        } else {
+            let len = len_sq.sqrt();
+            Some(Quat::new(self.x / len, self.y / len, self.z / len, self.w / len))
+        }
+    }
+
+    /// Returns the columns of the 3x3 rotation matrix equivalent to this quaternion,
+    /// i.e. where the local `X`, `Y`, and `Z` axes are mapped to.
+    pub(crate) fn to_basis(self) -> (Vec3, Vec3, Vec3) {
+        let (x, y, z, w) = (self.x, self.y, self.z, self.w);
+        let (x2, y2, z2) = (x + x, y + y, z + z);
+        let (xx, xy, xz) = (x * x2, x * y2, x * z2);
+        let (yy, yz, zz) = (y * y2, y * z2, z * z2);
+        let (wx, wy, wz) = (w * x2, w * y2, w * z2);
+
+        let col_x = Vec3::new(1.0 - (yy + zz), xy + wz, xz - wy);
+        let col_y = Vec3::new(xy - wz, 1.0 - (xx + zz), yz + wx);
+        let col_z = Vec3::new(xz + wy, yz - wx, 1.0 - (xx + yy));
+        (col_x, col_y, col_z)
+    }
+
+    /// Builds the quaternion representing the rotation whose matrix columns are
+    /// `col_x`, `col_y`, `col_z` (i.e. where the local `X`, `Y`, and `Z` axes are mapped to),
+    /// using Shepperd's method to avoid the singularities of the naive trace formula.
+    pub(crate) fn from_basis(col_x: Vec3, col_y: Vec3, col_z: Vec3) -> Quat {
+        let (m00, m10, m20) = (col_x.x, col_x.y, col_x.z);
+        let (m01, m11, m21) = (col_y.x, col_y.y, col_y.z);
+        let (m02, m12, m22) = (col_z.x, col_z.y, col_z.z);
+
+        let candidates = [
+            1.0 + m00 + m11 + m22,
+            1.0 + m00 - m11 - m22,
+            1.0 - m00 + m11 - m22,
+            1.0 - m00 - m11 + m22,
+        ];
+
+        let mut largest = 0usize;
+        for i in 1..4 {
+            if candidates[i] > candidates[largest] {
+                largest = i;
+            }
+        }
+
+        let t = candidates[largest].max(0.0).sqrt();
+        let s = 0.5 / t;
+
+        match largest {
+            0 => Quat::new((m21 - m12) * s, (m02 - m20) * s, (m10 - m01) * s, 0.5 * t),
+            1 => Quat::new(0.5 * t, (m01 + m10) * s, (m20 + m02) * s, (m21 - m12) * s),
+            2 => Quat::new((m01 + m10) * s, 0.5 * t, (m12 + m21) * s, (m02 - m20) * s),
+            _ => Quat::new((m20 + m02) * s, (m12 + m21) * s, 0.5 * t, (m10 - m01) * s),
+        }
+    }
+
+    /// Builds the rotation that points `axes`'s local forward direction at `forward`,
+    /// using `up` as a reference up direction, respecting `axes`'s handedness.
+    ///
+    /// Returns `None` if `forward` and `up` are too small or nearly parallel, the same
+    /// failure case as [`Axes::try_right_handed`]/[`Axes::try_left_handed`].
+    pub fn look_rotation(forward: Vec3, up: Vec3, axes: &Axes, epsilon: Scalar) -> Option<Quat> {
+        let eps = Vec3::new(epsilon, epsilon, epsilon);
+        let is_right_handed = axes
+            .right
+            .approx_eq_eps(&axes.forward.cross(axes.up), &eps);
+
+        let target = if is_right_handed {
+            Axes::try_right_handed(up, forward, epsilon)?
+        } else {
+            Axes::try_left_handed(up, forward, epsilon)?
+        };
+
+        // The rotation mapping axes.{right,up,forward} to target.{right,up,forward};
+        // since axes is orthonormal its inverse is its transpose, so the resulting
+        // matrix's columns are these weighted sums of target's axes.
+        let col_x = target.right * axes.right.x + target.up * axes.up.x + target.forward * axes.forward.x;
+        let col_y = target.right * axes.right.y + target.up * axes.up.y + target.forward * axes.forward.y;
+        let col_z = target.right * axes.right.z + target.up * axes.up.z + target.forward * axes.forward.z;
+
+        Some(Quat::from_basis(col_x, col_y, col_z))
+    }
+}
+
+/// Rotates `rhs` by this quaternion.
+impl Mul<Vec3> for Quat {
+    type Output = Vec3;
+
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        let q = Vec3::new(self.x, self.y, self.z);
+        let t = q.cross(rhs) * 2.0;
+        rhs + t * self.w + q.cross(t)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impls {
+    use super::*;
+
+    impl From<nalgebra::Quaternion<Scalar>> for Quat {
+        #[inline(always)]
+        fn from(q: nalgebra::Quaternion<Scalar>) -> Self {
+            Self::new(q.i, q.j, q.k, q.w)
+        }
+    }
+    impl From<Quat> for nalgebra::Quaternion<Scalar> {
+        #[inline(always)]
+        fn from(q: Quat) -> Self {
+            Self::new(q.w, q.x, q.y, q.z)
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+mod glam_impls {
+    use super::*;
+
+    #[cfg(feature = "f32")]
+    impl From<glam::Quat> for Quat {
+        fn from(q: glam::Quat) -> Self {
+            Self {
+                x: q.x,
+                y: q.y,
+                z: q.z,
+                w: q.w,
+            }
+        }
+    }
+
+    #[cfg(feature = "f32")]
+    impl From<Quat> for glam::Quat {
+        fn from(q: Quat) -> Self {
+            Self::from_xyzw(q.x, q.y, q.z, q.w)
+        }
+    }
+
+    #[cfg(feature = "f64")]
+    impl From<glam::DQuat> for Quat {
+        fn from(q: glam::DQuat) -> Self {
+            Self {
+                x: q.x,
+                y: q.y,
+                z: q.z,
+                w: q.w,
+            }
+        }
+    }
+
+    #[cfg(feature = "f64")]
+    impl From<Quat> for glam::DQuat {
+        fn from(q: Quat) -> Self {
+            Self::from_xyzw(q.x, q.y, q.z, q.w)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identity_has_zero_vector_part() {
+        let q = Quat::IDENTITY;
+        assert_eq!(q.x, 0.0 as Scalar);
+        assert_eq!(q.y, 0.0 as Scalar);
+        assert_eq!(q.z, 0.0 as Scalar);
+        assert_eq!(q.w, 1.0 as Scalar);
+    }
+
+    #[test]
+    fn default_is_identity() {
+        assert_eq!(Quat::default(), Quat::IDENTITY);
+    }
+
+    #[test]
+    fn conjugate_negates_vector_part() {
+        let q = Quat::new(1.0 as Scalar, 2.0 as Scalar, 3.0 as Scalar, 4.0 as Scalar);
+        assert_eq!(
+            q.conjugate(),
+            Quat::new(-1.0 as Scalar, -2.0 as Scalar, -3.0 as Scalar, 4.0 as Scalar)
+        );
+    }
+
+    #[test]
+    fn mul_vec3_by_identity_is_unchanged() {
+        let v = Vec3::new(1.0 as Scalar, 2.0 as Scalar, 3.0 as Scalar);
+        assert_eq!(Quat::IDENTITY * v, v);
+    }
+
+    #[test]
+    fn mul_vec3_by_quarter_turn_rotates_90_degrees() {
+        // A quarter turn about +Y should map +X to -Z (right-handed convention).
+        let half_angle = (std::f64::consts::FRAC_PI_4) as Scalar;
+        let q = Quat::new(
+            0.0 as Scalar,
+            half_angle.sin(),
+            0.0 as Scalar,
+            half_angle.cos(),
+        );
+        let rotated = q * Vec3::new(1.0 as Scalar, 0.0 as Scalar, 0.0 as Scalar);
+        let expected = Vec3::new(0.0 as Scalar, 0.0 as Scalar, -1.0 as Scalar);
+        let epsilon = 1.0e-5 as Scalar;
+        assert!((rotated - expected).length() <= epsilon);
+    }
+
+    #[test]
+    fn identity_basis_roundtrips() {
+        let (x, y, z) = Quat::IDENTITY.to_basis();
+        let q = Quat::from_basis(x, y, z);
+        let epsilon = 1.0e-5 as Scalar;
+        assert!((q.dot(Quat::IDENTITY).abs() - 1.0 as Scalar).abs() <= epsilon);
+    }
+
+    #[test]
+    fn look_rotation_matching_convention_forward_is_identity() {
+        let axes = crate::conventions::DEFAULT;
+        let epsilon = 1.0e-5 as Scalar;
+        let q = Quat::look_rotation(axes.forward, axes.up, &axes, epsilon)
+            .expect("forward and up are not parallel");
+        assert!((q.dot(Quat::IDENTITY).abs() - 1.0 as Scalar).abs() <= epsilon);
+    }
+
+    #[test]
+    fn look_rotation_rotates_local_forward_to_the_target_forward() {
+        let axes = crate::conventions::DEFAULT;
+        let epsilon = 1.0e-5 as Scalar;
+        let target_forward = Vec3::new(1.0 as Scalar, 0.0 as Scalar, 0.0 as Scalar);
+        let q = Quat::look_rotation(target_forward, axes.up, &axes, epsilon)
+            .expect("forward and up are not parallel");
+        let rotated = q * axes.forward;
+        assert!((rotated - target_forward).length() <= epsilon);
+    }
+
+    #[test]
+    fn look_rotation_returns_none_for_parallel_forward_and_up() {
+        let axes = crate::conventions::DEFAULT;
+        let epsilon = 1.0e-5 as Scalar;
+        assert!(Quat::look_rotation(axes.up, axes.up, &axes, epsilon).is_none());
+    }
+}
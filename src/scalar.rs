@@ -0,0 +1,13 @@
+//! The floating-point scalar type used throughout this crate.
+
+/// The floating-point type backing every math type in this crate.
+///
+/// Controlled by the `f32` (default) and `f64` features.
+#[cfg(feature = "f32")]
+pub type Scalar = f32;
+
+/// The floating-point type backing every math type in this crate.
+///
+/// Controlled by the `f32` (default) and `f64` features.
+#[cfg(feature = "f64")]
+pub type Scalar = f64;
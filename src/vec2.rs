@@ -0,0 +1,485 @@
+use crate::Scalar;
+use spacetimedb::SpacetimeType;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A 2-dimensional vector with `x` and `y` components.
+///
+/// # Examples
+/// ```
+/// use spacetimedb_math::Vec2;
+///
+/// let v = Vec2::new(1.0, 2.0);
+/// assert_eq!(v.x, 1.0);
+/// assert_eq!(v.y, 2.0);
+/// ```
+#[derive(SpacetimeType, Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec2 {
+    /// X component.
+    pub x: Scalar,
+    /// Y component.
+    pub y: Scalar,
+}
+
+impl Vec2 {
+    // Basic Constants
+    pub const ZERO: Vec2 = Vec2::new(0.0, 0.0);
+    pub const ONE: Vec2 = Vec2::new(1.0, 1.0);
+
+    // Unit axis constants
+    pub const X: Vec2 = Vec2::new(1.0, 0.0);
+    pub const Y: Vec2 = Vec2::new(0.0, 1.0);
+    pub const NEG_X: Vec2 = Vec2::new(-1.0, 0.0);
+    pub const NEG_Y: Vec2 = Vec2::new(0.0, -1.0);
+
+    #[inline(always)]
+    pub const fn new(x: Scalar, y: Scalar) -> Self {
+        Vec2 { x, y }
+    }
+
+    /// Returns a vector with both components set to `s`.
+    #[inline(always)]
+    pub const fn splat(s: Scalar) -> Self {
+        Vec2::new(s, s)
+    }
+
+    /// Returns the dot product of this vector and `other`.
+    #[inline]
+    pub fn dot(&self, other: Vec2) -> Scalar {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns the 2D "perpendicular dot product" (a.k.a. the scalar cross product)
+    /// of this vector and `other`.
+    #[inline]
+    pub fn perp_dot(&self, other: Vec2) -> Scalar {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Returns the squared length (magnitude) of this vector.
+    #[inline]
+    pub fn length_squared(&self) -> Scalar {
+        self.x * self.x + self.y * self.y
+    }
+
+    /// Returns the length (magnitude) of this vector.
+    pub fn length(&self) -> Scalar {
+        self.length_squared().sqrt()
+    }
+
+    /// Returns the squared distance between this vector and `other`.
+    #[inline]
+    pub fn distance_squared(&self, other: Vec2) -> Scalar {
+        let dx = other.x - self.x;
+        let dy = other.y - self.y;
+        dx * dx + dy * dy
+    }
+
+    /// Returns the distance between this vector and `other`.
+    pub fn distance(&self, other: Vec2) -> Scalar {
+        self.distance_squared(other).sqrt()
+    }
+
+    /// Returns a normalized vector, or `fallback` if length is below `epsilon`.
+    pub fn normalize_or(&self, epsilon: Scalar, fallback: Vec2) -> Vec2 {
+        let len_sq = self.length_squared();
+        let epsilon_sq = epsilon * epsilon;
+        if len_sq <= epsilon_sq {
+            fallback
+        } else {
+            let len = len_sq.sqrt();
+            Vec2::new(self.x / len, self.y / len)
+        }
+    }
+
+    /// Returns a normalized vector, or `Vec2::ZERO` if length is below `epsilon`.
+    pub fn normalize_or_zero(&self, epsilon: Scalar) -> Vec2 {
+        self.normalize_or(epsilon, Vec2::ZERO)
+    }
+
+    /// Attempts to normalize this vector, returning `None` if length is below `epsilon`.
+    pub fn try_normalize(&self, epsilon: Scalar) -> Option<Vec2> {
+        let len_sq = self.length_squared();
+        let epsilon_sq = epsilon * epsilon;
+        if len_sq <= epsilon_sq {
+            None
+        } else {
+            let len = len_sq.sqrt();
+            Some(Vec2::new(self.x / len, self.y / len))
+        }
+    }
+
+    /// Returns the projection of this vector onto `onto`, or `Vec2::ZERO` if
+    /// `onto`'s length is below `epsilon`.
+    pub fn project_onto(&self, onto: Vec2, epsilon: Scalar) -> Vec2 {
+        let denom = onto.length_squared();
+        if denom <= epsilon * epsilon {
+            Vec2::ZERO
+        } else {
+            onto * (self.dot(onto) / denom)
+        }
+    }
+
+    /// Returns the component of this vector orthogonal to `onto`, i.e. what's left
+    /// after subtracting the [`Vec2::project_onto`] component.
+    pub fn reject_from(&self, onto: Vec2, epsilon: Scalar) -> Vec2 {
+        *self - self.project_onto(onto, epsilon)
+    }
+
+    /// Reflects this vector off a surface with the given unit `normal`.
+    pub fn reflect(&self, normal: Vec2) -> Vec2 {
+        *self - normal * (2.0 * self.dot(normal))
+    }
+
+    /// Returns the signed angle in radians from this vector to `other`, in
+    /// `(-π, π]`: positive when `other` is counter-clockwise from this vector.
+    ///
+    /// Uses `atan2(perp_dot, dot)` (`perp_dot` being the 2D analogue of the cross
+    /// product) rather than `acos(dot)` for numerical stability near parallel vectors.
+    pub fn angle_between(&self, other: Vec2) -> Scalar {
+        self.perp_dot(other).atan2(self.dot(other))
+    }
+
+    /// Linearly interpolates between this vector and `other` by `t`.
+    pub fn lerp(&self, other: Vec2, t: Scalar) -> Vec2 {
+        *self + (other - *self) * t
+    }
+
+    /// Returns the component-wise minimum of this vector and `other`.
+    #[inline]
+    pub fn min(&self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// Returns the component-wise maximum of this vector and `other`.
+    #[inline]
+    pub fn max(&self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    /// Clamps each component of this vector between the corresponding components of `lo` and `hi`.
+    #[inline]
+    pub fn clamp(&self, lo: Vec2, hi: Vec2) -> Vec2 {
+        Vec2::new(self.x.clamp(lo.x, hi.x), self.y.clamp(lo.y, hi.y))
+    }
+
+    /// Returns a vector with the absolute value of each component.
+    #[inline]
+    pub fn abs(&self) -> Vec2 {
+        Vec2::new(self.x.abs(), self.y.abs())
+    }
+
+    /// Returns the smallest of this vector's components.
+    #[inline]
+    pub fn min_element(&self) -> Scalar {
+        self.x.min(self.y)
+    }
+
+    /// Returns the largest of this vector's components.
+    #[inline]
+    pub fn max_element(&self) -> Scalar {
+        self.x.max(self.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    #[inline]
+    fn add(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Vec2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    #[inline]
+    fn sub(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Vec2) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    #[inline]
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+/// Component-wise product. For the dot product, see [`Vec2::dot`].
+impl Mul<Vec2> for Vec2 {
+    type Output = Vec2;
+
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x * rhs.x, self.y * rhs.y)
+    }
+}
+
+impl Mul<Scalar> for Vec2 {
+    type Output = Vec2;
+
+    #[inline]
+    fn mul(self, rhs: Scalar) -> Vec2 {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl MulAssign<Scalar> for Vec2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Scalar) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<Scalar> for Vec2 {
+    type Output = Vec2;
+
+    #[inline]
+    fn div(self, rhs: Scalar) -> Vec2 {
+        Vec2::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl DivAssign<Scalar> for Vec2 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Scalar) {
+        *self = *self / rhs;
+    }
+}
+
+impl Sum for Vec2 {
+    fn sum<I: Iterator<Item = Vec2>>(iter: I) -> Vec2 {
+        iter.fold(Vec2::ZERO, Add::add)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impls {
+    use super::*;
+
+    impl From<nalgebra::Vector2<Scalar>> for Vec2 {
+        #[inline(always)]
+        fn from(v: nalgebra::Vector2<Scalar>) -> Self {
+            Self::new(v.x, v.y)
+        }
+    }
+    impl From<Vec2> for nalgebra::Vector2<Scalar> {
+        #[inline(always)]
+        fn from(v: Vec2) -> Self {
+            Self::new(v.x, v.y)
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+mod glam_impls {
+    use super::*;
+
+    #[cfg(feature = "f32")]
+    impl From<glam::Vec2> for Vec2 {
+        fn from(v: glam::Vec2) -> Self {
+            Self { x: v.x, y: v.y }
+        }
+    }
+
+    #[cfg(feature = "f32")]
+    impl From<Vec2> for glam::Vec2 {
+        fn from(v: Vec2) -> Self {
+            Self::new(v.x, v.y)
+        }
+    }
+
+    #[cfg(feature = "f64")]
+    impl From<glam::DVec2> for Vec2 {
+        fn from(v: glam::DVec2) -> Self {
+            Self { x: v.x, y: v.y }
+        }
+    }
+
+    #[cfg(feature = "f64")]
+    impl From<Vec2> for glam::DVec2 {
+        fn from(v: Vec2) -> Self {
+            Self::new(v.x, v.y)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constants_match_constructor() {
+        assert_eq!(Vec2::ZERO, Vec2::new(0.0 as Scalar, 0.0 as Scalar));
+        assert_eq!(Vec2::ONE, Vec2::new(1.0 as Scalar, 1.0 as Scalar));
+    }
+
+    #[test]
+    fn vec2_dot_is_sum_of_component_products() {
+        let a = Vec2::new(1.0 as Scalar, 2.0 as Scalar);
+        let b = Vec2::new(3.0 as Scalar, 4.0 as Scalar);
+        assert_eq!(a.dot(b), 11.0 as Scalar);
+    }
+
+    #[test]
+    fn vec2_perp_dot_matches_scalar_cross() {
+        let a = Vec2::new(1.0 as Scalar, 0.0 as Scalar);
+        let b = Vec2::new(0.0 as Scalar, 1.0 as Scalar);
+        assert_eq!(a.perp_dot(b), 1.0 as Scalar);
+    }
+
+    #[test]
+    fn vec2_length_squared_is_sum_of_squares() {
+        let v = Vec2::new(3.0 as Scalar, 4.0 as Scalar);
+        assert_eq!(v.length_squared(), 25.0 as Scalar);
+    }
+
+    #[test]
+    fn vec2_add_and_sub_are_componentwise() {
+        let a = Vec2::new(1.0 as Scalar, 2.0 as Scalar);
+        let b = Vec2::new(3.0 as Scalar, 4.0 as Scalar);
+        assert_eq!(a + b, Vec2::new(4.0 as Scalar, 6.0 as Scalar));
+        assert_eq!(b - a, Vec2::new(2.0 as Scalar, 2.0 as Scalar));
+    }
+
+    #[test]
+    fn vec2_neg_negates_all_components() {
+        let v = Vec2::new(1.0 as Scalar, -2.0 as Scalar);
+        assert_eq!(-v, Vec2::new(-1.0 as Scalar, 2.0 as Scalar));
+    }
+
+    #[test]
+    fn vec2_mul_and_div_scalar_scale_components() {
+        let v = Vec2::new(1.0 as Scalar, 2.0 as Scalar);
+        assert_eq!(v * 2.0 as Scalar, Vec2::new(2.0 as Scalar, 4.0 as Scalar));
+        assert_eq!((v * 2.0 as Scalar) / 2.0 as Scalar, v);
+    }
+
+    #[test]
+    fn vec2_sum_adds_all_elements() {
+        let vs = [
+            Vec2::new(1.0 as Scalar, 0.0 as Scalar),
+            Vec2::new(0.0 as Scalar, 1.0 as Scalar),
+        ];
+        let total: Vec2 = vs.into_iter().sum();
+        assert_eq!(total, Vec2::ONE);
+    }
+
+    #[test]
+    fn vec2_project_onto_keeps_only_the_parallel_component() {
+        let v = Vec2::new(3.0 as Scalar, 4.0 as Scalar);
+        let onto = Vec2::new(1.0 as Scalar, 0.0 as Scalar);
+        assert_eq!(
+            v.project_onto(onto, 1.0e-5 as Scalar),
+            Vec2::new(3.0 as Scalar, 0.0 as Scalar)
+        );
+    }
+
+    #[test]
+    fn vec2_reject_from_keeps_only_the_orthogonal_component() {
+        let v = Vec2::new(3.0 as Scalar, 4.0 as Scalar);
+        let onto = Vec2::new(1.0 as Scalar, 0.0 as Scalar);
+        assert_eq!(
+            v.reject_from(onto, 1.0e-5 as Scalar),
+            Vec2::new(0.0 as Scalar, 4.0 as Scalar)
+        );
+    }
+
+    #[test]
+    fn vec2_reflect_bounces_off_a_unit_normal() {
+        let v = Vec2::new(1.0 as Scalar, -1.0 as Scalar);
+        let normal = Vec2::new(0.0 as Scalar, 1.0 as Scalar);
+        assert_eq!(v.reflect(normal), Vec2::new(1.0 as Scalar, 1.0 as Scalar));
+    }
+
+    #[test]
+    fn vec2_angle_between_perpendicular_vectors_is_half_pi() {
+        let a = Vec2::new(1.0 as Scalar, 0.0 as Scalar);
+        let b = Vec2::new(0.0 as Scalar, 1.0 as Scalar);
+        let epsilon = 1.0e-5 as Scalar;
+        assert!((a.angle_between(b) - std::f64::consts::FRAC_PI_2 as Scalar).abs() <= epsilon);
+    }
+
+    #[test]
+    fn vec2_lerp_interpolates_between_endpoints() {
+        let a = Vec2::ZERO;
+        let b = Vec2::new(10.0 as Scalar, 0.0 as Scalar);
+        assert_eq!(a.lerp(b, 0.5 as Scalar), Vec2::new(5.0 as Scalar, 0.0 as Scalar));
+    }
+
+    #[test]
+    fn vec2_axis_constants_are_unit_vectors() {
+        assert_eq!(Vec2::X, Vec2::new(1.0 as Scalar, 0.0 as Scalar));
+        assert_eq!(Vec2::NEG_X, Vec2::new(-1.0 as Scalar, 0.0 as Scalar));
+        assert_eq!(Vec2::Y, Vec2::new(0.0 as Scalar, 1.0 as Scalar));
+        assert_eq!(Vec2::NEG_Y, Vec2::new(0.0 as Scalar, -1.0 as Scalar));
+    }
+
+    #[test]
+    fn vec2_splat_fills_all_components() {
+        assert_eq!(Vec2::splat(2.0 as Scalar), Vec2::new(2.0 as Scalar, 2.0 as Scalar));
+    }
+
+    #[test]
+    fn vec2_min_and_max_are_componentwise() {
+        let a = Vec2::new(1.0 as Scalar, 5.0 as Scalar);
+        let b = Vec2::new(3.0 as Scalar, 2.0 as Scalar);
+        assert_eq!(a.min(b), Vec2::new(1.0 as Scalar, 2.0 as Scalar));
+        assert_eq!(a.max(b), Vec2::new(3.0 as Scalar, 5.0 as Scalar));
+    }
+
+    #[test]
+    fn vec2_clamp_restricts_each_component() {
+        let v = Vec2::new(-1.0 as Scalar, 5.0 as Scalar);
+        assert_eq!(v.clamp(Vec2::ZERO, Vec2::ONE), Vec2::new(0.0 as Scalar, 1.0 as Scalar));
+    }
+
+    #[test]
+    fn vec2_abs_takes_absolute_value_of_each_component() {
+        let v = Vec2::new(-1.0 as Scalar, 2.0 as Scalar);
+        assert_eq!(v.abs(), Vec2::new(1.0 as Scalar, 2.0 as Scalar));
+    }
+
+    #[test]
+    fn vec2_min_element_and_max_element_find_extremes() {
+        let v = Vec2::new(3.0 as Scalar, -1.0 as Scalar);
+        assert_eq!(v.min_element(), -1.0 as Scalar);
+        assert_eq!(v.max_element(), 3.0 as Scalar);
+    }
+
+    #[test]
+    fn vec2_normalize_or_uses_fallback_when_too_small() {
+        let v = Vec2::ZERO;
+        let fallback = Vec2::new(1.0 as Scalar, 0.0 as Scalar);
+        assert_eq!(v.normalize_or(1.0e-5 as Scalar, fallback), fallback);
+    }
+
+    #[test]
+    fn vec2_normalize_produces_unit_length_for_non_zero() {
+        let v = Vec2::new(3.0 as Scalar, 4.0 as Scalar);
+        let normalized = v
+            .try_normalize(1.0e-5 as Scalar)
+            .expect("expected unit vector");
+        let epsilon = 1.0e-5 as Scalar;
+        assert!((normalized.length() - 1.0 as Scalar).abs() <= epsilon);
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{Scalar, Vec2};
 use spacetimedb::SpacetimeType;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// A 3-dimensional vector with `x`, `y`, and `z` components.
 ///
@@ -28,11 +30,25 @@ impl Vec3 {
     pub const ZERO: Vec3 = Vec3::new(0.0, 0.0, 0.0);
     pub const ONE: Vec3 = Vec3::new(1.0, 1.0, 1.0);
 
+    // Unit axis constants
+    pub const X: Vec3 = Vec3::new(1.0, 0.0, 0.0);
+    pub const Y: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+    pub const Z: Vec3 = Vec3::new(0.0, 0.0, 1.0);
+    pub const NEG_X: Vec3 = Vec3::new(-1.0, 0.0, 0.0);
+    pub const NEG_Y: Vec3 = Vec3::new(0.0, -1.0, 0.0);
+    pub const NEG_Z: Vec3 = Vec3::new(0.0, 0.0, -1.0);
+
     #[inline(always)]
     pub const fn new(x: Scalar, y: Scalar, z: Scalar) -> Self {
         Vec3 { x, y, z }
     }
 
+    /// Returns a vector with all three components set to `s`.
+    #[inline(always)]
+    pub const fn splat(s: Scalar) -> Self {
+        Vec3::new(s, s, s)
+    }
+
     /// Returns the XY components of this vector.
     #[inline]
     pub const fn xy(&self) -> Vec2 {
@@ -114,6 +130,170 @@ impl Vec3 {
             Some(Vec3::new(self.x / len, self.y / len, self.z / len))
         }
     }
+
+    /// Returns the projection of this vector onto `onto`, or `Vec3::ZERO` if
+    /// `onto`'s length is below `epsilon`.
+    pub fn project_onto(&self, onto: Vec3, epsilon: Scalar) -> Vec3 {
+        let denom = onto.length_squared();
+        if denom <= epsilon * epsilon {
+            Vec3::ZERO
+        } else {
+            onto * (self.dot(onto) / denom)
+        }
+    }
+
+    /// Returns the component of this vector orthogonal to `onto`, i.e. what's left
+    /// after subtracting the [`Vec3::project_onto`] component.
+    pub fn reject_from(&self, onto: Vec3, epsilon: Scalar) -> Vec3 {
+        *self - self.project_onto(onto, epsilon)
+    }
+
+    /// Reflects this vector off a surface with the given unit `normal`.
+    pub fn reflect(&self, normal: Vec3) -> Vec3 {
+        *self - normal * (2.0 * self.dot(normal))
+    }
+
+    /// Returns the angle in radians between this vector and `other`.
+    ///
+    /// Uses `atan2(cross.length(), dot)` rather than `acos(dot)` for numerical
+    /// stability near parallel and anti-parallel vectors.
+    pub fn angle_between(&self, other: Vec3) -> Scalar {
+        self.cross(other).length().atan2(self.dot(other))
+    }
+
+    /// Linearly interpolates between this vector and `other` by `t`.
+    pub fn lerp(&self, other: Vec3, t: Scalar) -> Vec3 {
+        *self + (other - *self) * t
+    }
+
+    /// Returns the component-wise minimum of this vector and `other`.
+    #[inline]
+    pub fn min(&self, other: Vec3) -> Vec3 {
+        Vec3::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z))
+    }
+
+    /// Returns the component-wise maximum of this vector and `other`.
+    #[inline]
+    pub fn max(&self, other: Vec3) -> Vec3 {
+        Vec3::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z))
+    }
+
+    /// Clamps each component of this vector between the corresponding components of `lo` and `hi`.
+    #[inline]
+    pub fn clamp(&self, lo: Vec3, hi: Vec3) -> Vec3 {
+        Vec3::new(
+            self.x.clamp(lo.x, hi.x),
+            self.y.clamp(lo.y, hi.y),
+            self.z.clamp(lo.z, hi.z),
+        )
+    }
+
+    /// Returns a vector with the absolute value of each component.
+    #[inline]
+    pub fn abs(&self) -> Vec3 {
+        Vec3::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    /// Returns the smallest of this vector's components.
+    #[inline]
+    pub fn min_element(&self) -> Scalar {
+        self.x.min(self.y).min(self.z)
+    }
+
+    /// Returns the largest of this vector's components.
+    #[inline]
+    pub fn max_element(&self) -> Scalar {
+        self.x.max(self.y).max(self.z)
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Vec3;
+
+    #[inline]
+    fn add(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Vec3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Vec3) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Vec3;
+
+    #[inline]
+    fn sub(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl SubAssign for Vec3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Vec3) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Vec3;
+
+    #[inline]
+    fn neg(self) -> Vec3 {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// Component-wise product. For the dot product, see [`Vec3::dot`].
+impl Mul<Vec3> for Vec3 {
+    type Output = Vec3;
+
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x * rhs.x, self.y * rhs.y, self.z * rhs.z)
+    }
+}
+
+impl Mul<Scalar> for Vec3 {
+    type Output = Vec3;
+
+    #[inline]
+    fn mul(self, rhs: Scalar) -> Vec3 {
+        Vec3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl MulAssign<Scalar> for Vec3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Scalar) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<Scalar> for Vec3 {
+    type Output = Vec3;
+
+    #[inline]
+    fn div(self, rhs: Scalar) -> Vec3 {
+        Vec3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl DivAssign<Scalar> for Vec3 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Scalar) {
+        *self = *self / rhs;
+    }
+}
+
+impl Sum for Vec3 {
+    fn sum<I: Iterator<Item = Vec3>>(iter: I) -> Vec3 {
+        iter.fold(Vec3::ZERO, Add::add)
+    }
 }
 
 #[cfg(feature = "nalgebra")]
@@ -253,6 +433,167 @@ mod tests {
         assert!((actual - expected).abs() <= epsilon);
     }
 
+    #[test]
+    fn vec3_add_and_sub_are_componentwise() {
+        let a = Vec3::new(1.0 as Scalar, 2.0 as Scalar, 3.0 as Scalar);
+        let b = Vec3::new(4.0 as Scalar, 5.0 as Scalar, 6.0 as Scalar);
+        assert_eq!(a + b, Vec3::new(5.0 as Scalar, 7.0 as Scalar, 9.0 as Scalar));
+        assert_eq!(b - a, Vec3::new(3.0 as Scalar, 3.0 as Scalar, 3.0 as Scalar));
+    }
+
+    #[test]
+    fn vec3_add_assign_and_sub_assign_match_add_and_sub() {
+        let mut a = Vec3::new(1.0 as Scalar, 2.0 as Scalar, 3.0 as Scalar);
+        let b = Vec3::new(4.0 as Scalar, 5.0 as Scalar, 6.0 as Scalar);
+        a += b;
+        assert_eq!(a, Vec3::new(5.0 as Scalar, 7.0 as Scalar, 9.0 as Scalar));
+        a -= b;
+        assert_eq!(a, Vec3::new(1.0 as Scalar, 2.0 as Scalar, 3.0 as Scalar));
+    }
+
+    #[test]
+    fn vec3_neg_negates_all_components() {
+        let v = Vec3::new(1.0 as Scalar, -2.0 as Scalar, 3.0 as Scalar);
+        assert_eq!(-v, Vec3::new(-1.0 as Scalar, 2.0 as Scalar, -3.0 as Scalar));
+    }
+
+    #[test]
+    fn vec3_mul_vec3_is_componentwise_product() {
+        let a = Vec3::new(1.0 as Scalar, 2.0 as Scalar, 3.0 as Scalar);
+        let b = Vec3::new(4.0 as Scalar, 5.0 as Scalar, 6.0 as Scalar);
+        assert_eq!(a * b, Vec3::new(4.0 as Scalar, 10.0 as Scalar, 18.0 as Scalar));
+    }
+
+    #[test]
+    fn vec3_mul_and_div_scalar_scale_components() {
+        let v = Vec3::new(1.0 as Scalar, 2.0 as Scalar, 3.0 as Scalar);
+        assert_eq!(v * 2.0 as Scalar, Vec3::new(2.0 as Scalar, 4.0 as Scalar, 6.0 as Scalar));
+        assert_eq!(
+            (v * 2.0 as Scalar) / 2.0 as Scalar,
+            v
+        );
+    }
+
+    #[test]
+    fn vec3_mul_assign_and_div_assign_scale_in_place() {
+        let mut v = Vec3::new(1.0 as Scalar, 2.0 as Scalar, 3.0 as Scalar);
+        v *= 2.0 as Scalar;
+        assert_eq!(v, Vec3::new(2.0 as Scalar, 4.0 as Scalar, 6.0 as Scalar));
+        v /= 2.0 as Scalar;
+        assert_eq!(v, Vec3::new(1.0 as Scalar, 2.0 as Scalar, 3.0 as Scalar));
+    }
+
+    #[test]
+    fn vec3_sum_adds_all_elements() {
+        let vs = [
+            Vec3::new(1.0 as Scalar, 0.0 as Scalar, 0.0 as Scalar),
+            Vec3::new(0.0 as Scalar, 1.0 as Scalar, 0.0 as Scalar),
+            Vec3::new(0.0 as Scalar, 0.0 as Scalar, 1.0 as Scalar),
+        ];
+        let total: Vec3 = vs.into_iter().sum();
+        assert_eq!(total, Vec3::ONE);
+    }
+
+    #[test]
+    fn vec3_project_onto_keeps_only_the_parallel_component() {
+        let v = Vec3::new(3.0 as Scalar, 4.0 as Scalar, 0.0 as Scalar);
+        let onto = Vec3::new(1.0 as Scalar, 0.0 as Scalar, 0.0 as Scalar);
+        assert_eq!(
+            v.project_onto(onto, 1.0e-5 as Scalar),
+            Vec3::new(3.0 as Scalar, 0.0 as Scalar, 0.0 as Scalar)
+        );
+    }
+
+    #[test]
+    fn vec3_project_onto_near_zero_returns_zero() {
+        let v = Vec3::new(3.0 as Scalar, 4.0 as Scalar, 0.0 as Scalar);
+        assert_eq!(v.project_onto(Vec3::ZERO, 1.0e-5 as Scalar), Vec3::ZERO);
+    }
+
+    #[test]
+    fn vec3_reject_from_keeps_only_the_orthogonal_component() {
+        let v = Vec3::new(3.0 as Scalar, 4.0 as Scalar, 0.0 as Scalar);
+        let onto = Vec3::new(1.0 as Scalar, 0.0 as Scalar, 0.0 as Scalar);
+        assert_eq!(
+            v.reject_from(onto, 1.0e-5 as Scalar),
+            Vec3::new(0.0 as Scalar, 4.0 as Scalar, 0.0 as Scalar)
+        );
+    }
+
+    #[test]
+    fn vec3_reflect_bounces_off_a_unit_normal() {
+        let v = Vec3::new(1.0 as Scalar, -1.0 as Scalar, 0.0 as Scalar);
+        let normal = Vec3::new(0.0 as Scalar, 1.0 as Scalar, 0.0 as Scalar);
+        assert_eq!(v.reflect(normal), Vec3::new(1.0 as Scalar, 1.0 as Scalar, 0.0 as Scalar));
+    }
+
+    #[test]
+    fn vec3_angle_between_parallel_vectors_is_zero() {
+        let v = Vec3::new(1.0 as Scalar, 0.0 as Scalar, 0.0 as Scalar);
+        assert_eq!(v.angle_between(v), 0.0 as Scalar);
+    }
+
+    #[test]
+    fn vec3_angle_between_perpendicular_vectors_is_half_pi() {
+        let a = Vec3::new(1.0 as Scalar, 0.0 as Scalar, 0.0 as Scalar);
+        let b = Vec3::new(0.0 as Scalar, 1.0 as Scalar, 0.0 as Scalar);
+        let epsilon = 1.0e-5 as Scalar;
+        assert!((a.angle_between(b) - std::f64::consts::FRAC_PI_2 as Scalar).abs() <= epsilon);
+    }
+
+    #[test]
+    fn vec3_lerp_interpolates_between_endpoints() {
+        let a = Vec3::ZERO;
+        let b = Vec3::new(10.0 as Scalar, 0.0 as Scalar, 0.0 as Scalar);
+        assert_eq!(a.lerp(b, 0.5 as Scalar), Vec3::new(5.0 as Scalar, 0.0 as Scalar, 0.0 as Scalar));
+    }
+
+    #[test]
+    fn vec3_axis_constants_are_unit_vectors() {
+        assert_eq!(Vec3::X, Vec3::new(1.0 as Scalar, 0.0 as Scalar, 0.0 as Scalar));
+        assert_eq!(Vec3::NEG_X, Vec3::new(-1.0 as Scalar, 0.0 as Scalar, 0.0 as Scalar));
+        assert_eq!(Vec3::Y, Vec3::new(0.0 as Scalar, 1.0 as Scalar, 0.0 as Scalar));
+        assert_eq!(Vec3::NEG_Y, Vec3::new(0.0 as Scalar, -1.0 as Scalar, 0.0 as Scalar));
+        assert_eq!(Vec3::Z, Vec3::new(0.0 as Scalar, 0.0 as Scalar, 1.0 as Scalar));
+        assert_eq!(Vec3::NEG_Z, Vec3::new(0.0 as Scalar, 0.0 as Scalar, -1.0 as Scalar));
+    }
+
+    #[test]
+    fn vec3_splat_fills_all_components() {
+        assert_eq!(
+            Vec3::splat(2.0 as Scalar),
+            Vec3::new(2.0 as Scalar, 2.0 as Scalar, 2.0 as Scalar)
+        );
+    }
+
+    #[test]
+    fn vec3_min_and_max_are_componentwise() {
+        let a = Vec3::new(1.0 as Scalar, 5.0 as Scalar, -2.0 as Scalar);
+        let b = Vec3::new(3.0 as Scalar, 2.0 as Scalar, -4.0 as Scalar);
+        assert_eq!(a.min(b), Vec3::new(1.0 as Scalar, 2.0 as Scalar, -4.0 as Scalar));
+        assert_eq!(a.max(b), Vec3::new(3.0 as Scalar, 5.0 as Scalar, -2.0 as Scalar));
+    }
+
+    #[test]
+    fn vec3_clamp_restricts_each_component() {
+        let v = Vec3::new(-1.0 as Scalar, 5.0 as Scalar, 2.0 as Scalar);
+        let clamped = v.clamp(Vec3::ZERO, Vec3::ONE);
+        assert_eq!(clamped, Vec3::new(0.0 as Scalar, 1.0 as Scalar, 1.0 as Scalar));
+    }
+
+    #[test]
+    fn vec3_abs_takes_absolute_value_of_each_component() {
+        let v = Vec3::new(-1.0 as Scalar, 2.0 as Scalar, -3.0 as Scalar);
+        assert_eq!(v.abs(), Vec3::new(1.0 as Scalar, 2.0 as Scalar, 3.0 as Scalar));
+    }
+
+    #[test]
+    fn vec3_min_element_and_max_element_find_extremes() {
+        let v = Vec3::new(3.0 as Scalar, -1.0 as Scalar, 2.0 as Scalar);
+        assert_eq!(v.min_element(), -1.0 as Scalar);
+        assert_eq!(v.max_element(), 3.0 as Scalar);
+    }
+
     #[test]
     fn vec3_normalize_or_zero_handles_zero_length() {
         let v = Vec3::ZERO;